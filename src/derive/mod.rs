@@ -1,15 +1,17 @@
 use proc_macro::TokenStream;
+use proc_macro2::Span;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Lit, Meta, MetaNameValue, spanned::Spanned};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, MetaNameValue, NestedMeta,
+    spanned::Spanned,
+};
 use indoc::indoc;
 
 macro_rules! bail {( $err_msg:expr$(, $span:expr)? $(,)? ) => (
     {
         let mut _span = ::proc_macro2::Span::call_site();
         $( _span = $span; )?
-        return ::syn::Error::new(_span, $err_msg)
-                   .to_compile_error()
-                   .into()
+        return Err(::syn::Error::new(_span, $err_msg))
         ;
     }
 )}
@@ -20,40 +22,143 @@ pub fn label(input: TokenStream) -> TokenStream {
     let DeriveInput {
         ident,
         attrs,
-        data: _,
+        data,
         vis: _,
-        generics: _,
+        generics,
     } = parse_macro_input!(input);
 
-    // find the attribute we care about
-    let label_attr = attrs
-        .iter()
-        .find(|attr| attr.path.is_ident("label"));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    // bail if it doesn't exist
-    if label_attr.is_none() {
-        bail!(
-            r#"missing label attribute, e.g. #[label = "My label"]"#,
-            ident.span()
-        )
-    }
+    let ResolvedLabel { prelude, expr: label_expr } = match resolve_type_label(
+        &ident,
+        &attrs,
+        ident.span(),
+        &generics,
+        &impl_generics,
+        &ty_generics,
+        where_clause,
+    ) {
+        Ok(resolved) => resolved,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-    match label_attr.unwrap().parse_meta() {
-        Ok(Meta::NameValue(MetaNameValue {
-            lit: Lit::Str(label),
-            eq_token: _,
-            path: _
-        })) => {
+    match data {
+        Data::Enum(data_enum) => match label_enum(
+            &ident,
+            &label_expr,
+            &data_enum,
+            &impl_generics,
+            &ty_generics,
+            where_clause,
+        ) {
+            Ok(expanded) => TokenStream::from(quote! { #prelude #expanded }),
+            Err(err) => TokenStream::from(err.to_compile_error()),
+        },
+        _ => {
             // Build the output, possibly using quasi-quotation
             let expanded = quote! {
-                impl ::type_label::Label for #ident {
-                    const LABEL: &'static str = #label;
+                #prelude
+
+                impl #impl_generics ::type_label::Label for #ident #ty_generics #where_clause {
+                    const LABEL: &'static str = #label_expr;
                 }
             };
 
             // Hand the output tokens back to the compiler
             TokenStream::from(expanded)
-        },
+        }
+    }
+}
+
+/// Generate `impl Label` for an enum, adding a `variant_label` method so each
+/// variant can carry its own label alongside the enum's type-level `LABEL`.
+///
+/// Per-variant labels are opt-in: only enums with an explicit `#[label = "..."]`
+/// on at least one variant are required to label every variant (and get the
+/// `variant_label` override). An enum deriving `Label` with no variant attrs at
+/// all, like the crate's own `ActivityType` example, keeps compiling exactly as
+/// it did before per-variant labels existed, with `variant_label` falling back
+/// to the trait's default (`Self::LABEL`) for every variant.
+///
+/// Once opted in, every variant is checked, rather than bailing at the first bad
+/// one, so a user fixing several unlabeled variants at once sees all the errors
+/// together.
+fn label_enum(
+    ident: &syn::Ident,
+    label_expr: &proc_macro2::TokenStream,
+    data_enum: &syn::DataEnum,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: Option<&syn::WhereClause>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let per_variant_labels = data_enum
+        .variants
+        .iter()
+        .any(|variant| variant.attrs.iter().any(|attr| attr.path.is_ident("label")));
+
+    if !per_variant_labels {
+        return Ok(quote! {
+            impl #impl_generics ::type_label::Label for #ident #ty_generics #where_clause {
+                const LABEL: &'static str = #label_expr;
+            }
+        });
+    }
+
+    let mut variant_arms = Vec::with_capacity(data_enum.variants.len());
+    let mut errors: Option<syn::Error> = None;
+
+    for variant in &data_enum.variants {
+        match resolve_label(&variant.attrs, variant.ident.span()) {
+            Ok(variant_label) => {
+                let variant_ident = &variant.ident;
+                let pattern = match &variant.fields {
+                    Fields::Unit => quote! { #ident::#variant_ident },
+                    Fields::Unnamed(_) => quote! { #ident::#variant_ident(..) },
+                    Fields::Named(_) => quote! { #ident::#variant_ident { .. } },
+                };
+                variant_arms.push(quote! { #pattern => #variant_label });
+            }
+            Err(err) => match &mut errors {
+                Some(errors) => errors.combine(err),
+                None => errors = Some(err),
+            },
+        }
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::type_label::Label for #ident #ty_generics #where_clause {
+            const LABEL: &'static str = #label_expr;
+
+            fn variant_label(&self) -> &'static str {
+                match self {
+                    #(#variant_arms,)*
+                }
+            }
+        }
+    })
+}
+
+/// Resolve the label for an item (the type itself, or an enum variant) from
+/// its `#[label = "..."]` attr, falling back to its `///` doc comment.
+/// `missing_span` is used to point at the item if neither is present.
+fn resolve_label(attrs: &[syn::Attribute], missing_span: Span) -> Result<String, syn::Error> {
+    let label_attr = attrs.iter().find(|attr| attr.path.is_ident("label"));
+
+    let label_attr = match label_attr {
+        Some(label_attr) => label_attr,
+        None => return label_from_doc_comment(attrs, missing_span),
+    };
+
+    match label_attr.parse_meta() {
+        Ok(Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(label),
+            eq_token: _,
+            path: _
+        })) => Ok(label.value()),
         Ok(Meta::NameValue(MetaNameValue {
             lit: bad,
             eq_token: _,
@@ -62,18 +167,50 @@ pub fn label(input: TokenStream) -> TokenStream {
             indoc! {r#"
                 expected a string label, e.g. #[label = "My label"]
                                                         ^^^^^^^^^^ i.e. this part needs to be a string, with quotes!
-            "#},            
+
+                note: the label must be a string literal
+                help: wrap it in quotes, e.g. #[label = "My label"]
+            "#},
             bad.span()
         ),
+        // catch the common `#[label("...")]` mistake (list syntax instead of name-value) and
+        // recover the string inside it, so we can suggest the exact fix
+        Ok(Meta::List(list)) => {
+            let suggestion = list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Lit(Lit::Str(lit)) => Some(lit.value()),
+                _ => None,
+            });
+            let msg = match suggestion {
+                Some(label) => format!(
+                    indoc! {r#"
+                        expected name value syntax e.g. #[label = "My label"]
+                                                               ^^^ i.e. this eq sign is needed, not #[label("...")]
+
+                        note: found #[label(...)] attribute list syntax
+                        help: did you mean `#[label = "{}"]`?
+                    "#},
+                    label
+                ),
+                None => indoc! {r#"
+                    expected name value syntax e.g. #[label = "My label"]
+                                                           ^^^ i.e. this eq sign is needed, not #[label("...")]
+
+                    note: found #[label(...)] attribute list syntax, but couldn't find a string inside it
+                    help: use `#[label = "My label"]` instead
+                "#}
+                .to_string(),
+            };
+            bail!(msg, list.span())
+        }
         Ok(bad) => bail!(indoc!{r#"
                 expected name value syntax e.g. #[label = "My label"]
                                                        ^^^ i.e. this eq sign is needed, not #[Label("My label")]
-            "#},            
+            "#},
             bad.span()
         ),
         Err(_) => bail!(indoc!{r#"
             Error parsing label attribute.
-            
+
             Your label helper attribute should be above the type deriving Label,
             just below the #[derive(Label)].
             e.g.
@@ -83,3 +220,169 @@ pub fn label(input: TokenStream) -> TokenStream {
         "#}),
     }
 }
+
+/// The tokens needed to give a type its `LABEL`: `expr` is plugged directly
+/// into `const LABEL: &'static str = #expr;`, while `prelude` holds any extra
+/// items that expression depends on (only non-empty for
+/// `#[label(concat(...))]`, which needs a companion inherent impl — see
+/// [`build_concat_label`]) and is emitted just before the `impl Label` block.
+struct ResolvedLabel {
+    prelude: proc_macro2::TokenStream,
+    expr: proc_macro2::TokenStream,
+}
+
+/// Resolve the `LABEL` expression for a type, supporting the extra
+/// `#[label(concat("...", T))]` composition mode (`T` must be one of the
+/// type's own generic parameters, bound by `Label`) on top of everything
+/// [`resolve_label`] already understands.
+fn resolve_type_label(
+    ident: &syn::Ident,
+    attrs: &[syn::Attribute],
+    missing_span: Span,
+    generics: &syn::Generics,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: Option<&syn::WhereClause>,
+) -> Result<ResolvedLabel, syn::Error> {
+    let label_attr = attrs.iter().find(|attr| attr.path.is_ident("label"));
+
+    if let Some(label_attr) = label_attr {
+        if let Ok(Meta::List(list)) = label_attr.parse_meta() {
+            if let Some(concat_list) = as_concat_list(&list) {
+                return build_concat_label(
+                    ident,
+                    concat_list,
+                    generics,
+                    impl_generics,
+                    ty_generics,
+                    where_clause,
+                );
+            }
+        }
+    }
+
+    let label = resolve_label(attrs, missing_span)?;
+    Ok(ResolvedLabel {
+        prelude: quote! {},
+        expr: quote! { #label },
+    })
+}
+
+/// Recognise `#[label(concat(...))]`, as opposed to the `#[label("...")]`
+/// mistake that [`resolve_label`] already diagnoses.
+fn as_concat_list(list: &syn::MetaList) -> Option<&syn::MetaList> {
+    if list.nested.len() != 1 {
+        return None;
+    }
+    match list.nested.first().unwrap() {
+        NestedMeta::Meta(Meta::List(inner)) if inner.path.is_ident("concat") => Some(inner),
+        _ => None,
+    }
+}
+
+/// Build the `LABEL` expression for `#[label(concat(fragment, ...))]`, where each
+/// fragment is a string literal (emitted verbatim) or a generic parameter
+/// identifier (emitted as `<Param as Label>::LABEL`). Concatenation happens at
+/// const-eval time via [`type_label::__concat`], so no extra dependency is needed.
+fn build_concat_label(
+    ident: &syn::Ident,
+    list: &syn::MetaList,
+    generics: &syn::Generics,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: Option<&syn::WhereClause>,
+) -> Result<ResolvedLabel, syn::Error> {
+    let mut fragments = Vec::with_capacity(list.nested.len());
+
+    for nested in &list.nested {
+        match nested {
+            NestedMeta::Lit(Lit::Str(lit)) => {
+                let fragment = lit.value();
+                fragments.push(quote! { #fragment });
+            }
+            NestedMeta::Meta(Meta::Path(path)) => {
+                let ident = path.get_ident().ok_or_else(|| {
+                    syn::Error::new(path.span(), "expected a generic parameter identifier")
+                })?;
+                let is_generic_param = generics.type_params().any(|param| param.ident == *ident);
+                if !is_generic_param {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!(
+                            indoc! {r#"
+                                `{0}` is not a generic parameter of this type
+
+                                note: #[label(concat(...))] can only reference type parameters declared on this type
+                                help: add a `{0}: Label` bound to the type's generics
+                            "#},
+                            ident
+                        ),
+                    ));
+                }
+                fragments.push(quote! { <#ident as ::type_label::Label>::LABEL });
+            }
+            other => {
+                return Err(syn::Error::new(
+                    other.span(),
+                    "expected a string literal or a generic parameter identifier",
+                ));
+            }
+        }
+    }
+
+    // The composed bytes can't just live in a `let` inside `LABEL`'s own initializer:
+    // a `&'static str` built from `raw_parts(local.as_ptr(), len)` looks plausible but
+    // isn't sound — `local` is a transient value of *this* const's evaluation, not its
+    // own interned allocation, so the reference dangles once `LABEL` is done evaluating.
+    // Instead we give the buffer its own associated const (in a companion inherent
+    // impl, so it's a normal top-level item free to reference this `impl`'s generics,
+    // unlike a `const` nested inside a block, which would hit E0401). Evaluating that
+    // const item is what actually interns its value; `LABEL` then only ever borrows a
+    // field of it, never a local.
+    let prelude = quote! {
+        #[doc(hidden)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            const __LABEL_CONCAT_BUF: ([u8; ::type_label::__concat::MAX_LEN], usize) =
+                ::type_label::__concat::concat(&[ #(#fragments),* ]);
+        }
+    };
+
+    let expr = quote! {{
+        let bytes: &'static [u8; ::type_label::__concat::MAX_LEN] = &Self::__LABEL_CONCAT_BUF.0;
+        let len = Self::__LABEL_CONCAT_BUF.1;
+        unsafe {
+            ::core::str::from_utf8_unchecked(::core::slice::from_raw_parts(bytes.as_ptr(), len))
+        }
+    }};
+
+    Ok(ResolvedLabel { prelude, expr })
+}
+
+/// Derive a label from an item's `///` doc comment, used when there's no
+/// explicit `#[label = "..."]` attr.
+fn label_from_doc_comment(attrs: &[syn::Attribute], missing_span: Span) -> Result<String, syn::Error> {
+    let doc_lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(doc),
+                ..
+            })) => Some(doc.value()),
+            _ => None,
+        })
+        .collect();
+
+    if doc_lines.is_empty() {
+        bail!(
+            r#"missing label attribute, e.g. #[label = "My label"] (or a /// doc comment)"#,
+            missing_span
+        )
+    }
+
+    Ok(doc_lines
+        .iter()
+        .map(|line| line.trim_start())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
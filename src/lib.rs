@@ -24,6 +24,10 @@ fn main() {
 }
 ```
 
+If you omit the `#[label = "..."]` attribute, the derive falls back to the type's
+`///` doc comment, the same way [`displaydoc`](https://docs.rs/displaydoc) turns doc
+comments into `Display` output. `#[label = "..."]` always takes precedence when present.
+
 ## Motivation
 
 Generally, this trait is useful to provide a label for your types that is suitable for human users to read.
@@ -182,11 +186,40 @@ impl FromStr for ActivityType {
 }
 ```
 
+Rather than hand-writing that `Display` impl, `#[derive(Label)]` can compose `LABEL` out of
+string fragments and the labels of a type's own generic parameters, resolved at const time:
+```
+# #[cfg(feature = "derive")]
+# use type_label::Label;
+# #[cfg(feature = "derive")]
+#[derive(Label)]
+#[label(concat("Error parsing ", T))]
+pub struct ParseError<T: Label> {
+    _marker: std::marker::PhantomData<T>,
+}
+```
+which generates the equivalent of the `Display` impl above, but as a `const LABEL: &'static str`.
+
+## Metrics
+
+Enabling the `metrics` feature adds [`LabelMetrics`], an extension trait for every `T: Label`
+that pairs [`LABEL`](Label::LABEL) with [`variant_label`](Label::variant_label) as a
+`(&'static str, &'static str)` key/value pair, ready to hand to a labeled metric without
+allocating a `String` per call.
+
+This is BYO-metric: `LabelMetrics` doesn't integrate any particular metrics crate, it just
+gives you the `(key, value)` pair to register or increment against whatever backend you use.
+
 */
 
 #[cfg(feature = "derive")]
 pub use derive::Label;
 
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "metrics")]
+pub use metrics::LabelMetrics;
+
 /// Define a compile-time string label for your type
 pub trait Label {
     /// The label your type should have. It's completely static
@@ -195,6 +228,57 @@ pub trait Label {
     fn type_label(&self) -> &'static str {
         Self::LABEL
     }
+
+    /// The label of this specific value.
+    ///
+    /// For most types this is just [`LABEL`](Label::LABEL), since there's only one label for the
+    /// type as a whole. Enums deriving [`Label`] with a `#[label = "..."]` on each variant
+    /// override this to return the label of `self`'s specific variant, while [`LABEL`](Label::LABEL)
+    /// continues to describe the enum as a concept.
+    fn variant_label(&self) -> &'static str {
+        Self::LABEL
+    }
+}
+
+#[doc(hidden)]
+pub mod __concat {
+    //! Const-time string concatenation used by `#[derive(Label)]` to power
+    //! `#[label(concat("...", T))]`, where `T: Label` is a generic parameter of the
+    //! derived type. Not part of the public API: the derive macro is the only
+    //! intended caller, reached through `::type_label::__concat`.
+    //!
+    //! A fragment like `<T as Label>::LABEL` isn't known until `T` is monomorphized,
+    //! so the output can't be sized exactly by a const generic computed from it (that
+    //! needs unstable `generic_const_exprs`). Instead [`concat`] writes into a
+    //! fixed-capacity buffer capped at [`MAX_LEN`] bytes and reports how many of those
+    //! bytes were actually used.
+
+    /// Maximum total length, in bytes, of a `#[label(concat(...))]`-composed label.
+    /// Deliberately generous and independent of any generic parameter.
+    pub const MAX_LEN: usize = 256;
+
+    /// Concatenate `fragments` into a buffer of [`MAX_LEN`] bytes, returning the
+    /// buffer along with the number of bytes actually written.
+    ///
+    /// Panics (at compile time, since this only ever runs in a const context) if
+    /// `fragments` don't fit in [`MAX_LEN`] bytes.
+    pub const fn concat(fragments: &[&str]) -> ([u8; MAX_LEN], usize) {
+        let mut buf = [0u8; MAX_LEN];
+        let mut pos = 0;
+        let mut i = 0;
+        while i < fragments.len() {
+            let fragment = fragments[i].as_bytes();
+            let mut j = 0;
+            while j < fragment.len() {
+                assert!(pos < MAX_LEN, "#[label(concat(...))] fragments exceed MAX_LEN bytes");
+                buf[pos] = fragment[j];
+                pos += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+        (buf, pos)
+    }
 }
 
 /**
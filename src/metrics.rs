@@ -0,0 +1,26 @@
+//! Bridges [`Label`] to labeled metrics instrumentation (Prometheus-style
+//! `IntCounter`/`IntGauge` and friends), behind the `metrics` feature so the
+//! core crate stays dependency-free by default.
+//!
+//! This is BYO-metric: [`LabelMetrics`] only hands back the `(key, value)`
+//! pair, it doesn't pull in or register against any particular metrics
+//! crate. Pass the pair to whatever backend you already use.
+
+use crate::Label;
+
+/// Extension trait giving any `T: Label` a `(key, value)` pair suitable for a
+/// labeled metric, with zero per-call allocation.
+///
+/// Register your metric keyed by the type's concept-level [`LABEL`](Label::LABEL)
+/// and increment it by [`Label::variant_label`] (the variant label for enums
+/// deriving per-variant labels, or just [`LABEL`](Label::LABEL) otherwise) to
+/// avoid a stringly-typed label map.
+pub trait LabelMetrics: Label {
+    /// A `(key, value)` pair: this type's [`LABEL`](Label::LABEL) paired with
+    /// [`self.variant_label()`](Label::variant_label).
+    fn label_kv(&self) -> (&'static str, &'static str) {
+        (Self::LABEL, self.variant_label())
+    }
+}
+
+impl<T: Label> LabelMetrics for T {}
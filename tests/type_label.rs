@@ -16,6 +16,39 @@ pub struct Baz {}
 
 impl_label!(Baz, "baz label");
 
+/// quux label
+#[derive(Label, Debug)]
+pub struct Quux {}
+
+#[derive(Label, Debug)]
+#[label = "generic wrapper"]
+pub struct Wrapper<T: Label> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[derive(Label, Debug)]
+#[label(concat("parse error for ", T))]
+pub struct ParseError<T: Label> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[derive(Label, Debug)]
+#[label(concat("[", T, "] parse error for ", U))]
+pub struct MultiParseError<T: Label, U: Label> {
+    _marker: std::marker::PhantomData<(T, U)>,
+}
+
+#[derive(Label, Debug)]
+#[label = "activity type"]
+pub enum ActivityType {
+    #[label = "handoff"]
+    Handoff,
+    #[label = "invoke"]
+    Invoke(u8),
+    #[label = "message"]
+    Message { text: String },
+}
+
 
 fn assert_label<T: Label>(_: T, expected: &'static str) {
     let actual = <T as Label>::LABEL;
@@ -42,3 +75,65 @@ fn manual_label() {
 fn macro_rules_label() {
     assert_label(Baz {}, "baz label");
 }
+
+#[test]
+fn doc_comment_label() {
+    assert_label(Quux {}, "quux label");
+}
+
+#[test]
+fn generic_struct_label() {
+    assert_label(
+        Wrapper::<Foo> {
+            _marker: std::marker::PhantomData,
+        },
+        "generic wrapper",
+    );
+}
+
+#[test]
+fn composed_generic_label() {
+    assert_label(
+        ParseError::<Foo> {
+            _marker: std::marker::PhantomData,
+        },
+        "parse error for foo label",
+    );
+}
+
+#[test]
+fn composed_multi_fragment_label() {
+    assert_label(
+        MultiParseError::<Foo, Bar> {
+            _marker: std::marker::PhantomData,
+        },
+        "[foo label] parse error for bar label",
+    );
+}
+
+#[test]
+fn enum_type_label() {
+    assert_label(ActivityType::Handoff, "activity type");
+}
+
+#[test]
+fn enum_variant_label() {
+    assert_eq!(ActivityType::Handoff.variant_label(), "handoff");
+    assert_eq!(ActivityType::Invoke(0).variant_label(), "invoke");
+    assert_eq!(
+        ActivityType::Message { text: "hi".into() }.variant_label(),
+        "message"
+    );
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn label_kv() {
+    use type_label::LabelMetrics;
+
+    assert_eq!(Foo {}.label_kv(), ("foo label", "foo label"));
+    assert_eq!(
+        ActivityType::Handoff.label_kv(),
+        ("activity type", "handoff")
+    );
+}